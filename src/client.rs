@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{RoboatError, XCSRF_HEADER};
+
+/// The base client used to send requests to Roblox's APIs.
+///
+/// Cloning a [`Client`] is cheap; the underlying `reqwest` client and auth state are shared.
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub(crate) reqwest_client: reqwest::Client,
+    roblosecurity: Option<String>,
+    api_key: Option<String>,
+    xcsrf: Arc<RwLock<String>>,
+}
+
+impl Client {
+    /// Returns the `.ROBLOSECURITY` cookie formatted as a `Cookie` header value.
+    pub(crate) fn cookie_string(&self) -> Result<String, RoboatError> {
+        let roblosecurity = self.roblosecurity.as_ref().ok_or(RoboatError::MissingAuth)?;
+        Ok(format!(".ROBLOSECURITY={roblosecurity}"))
+    }
+
+    /// Returns the Open Cloud API key set on this client, or [`RoboatError::MissingAuth`] if none
+    /// is set.
+    pub(crate) fn api_key(&self) -> Result<String, RoboatError> {
+        self.api_key.clone().ok_or(RoboatError::MissingAuth)
+    }
+
+    pub(crate) async fn xcsrf(&self) -> String {
+        self.xcsrf.read().await.clone()
+    }
+
+    pub(crate) async fn set_xcsrf(&self, new_xcsrf: String) {
+        *self.xcsrf.write().await = new_xcsrf;
+    }
+
+    /// Turns a `reqwest` send result into either the successful response or a [`RoboatError`],
+    /// extracting a refreshed X-CSRF token from a `403` response into [`RoboatError::InvalidXcsrf`]
+    /// so callers can retry.
+    pub(crate) async fn validate_request_result(
+        request_result: Result<reqwest::Response, reqwest::Error>,
+    ) -> Result<reqwest::Response, RoboatError> {
+        let response = request_result.map_err(RoboatError::ReqwestError)?;
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            if let Some(xcsrf) = response.headers().get(XCSRF_HEADER) {
+                let xcsrf = xcsrf.to_str().unwrap_or_default().to_string();
+                return Err(RoboatError::InvalidXcsrf(xcsrf));
+            }
+        }
+
+        if !response.status().is_success() {
+            return Err(RoboatError::ResponseError(format!(
+                "request failed with status {}",
+                response.status()
+            )));
+        }
+
+        Ok(response)
+    }
+}
+
+/// A builder for [`Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    roblosecurity: Option<String>,
+    api_key: Option<String>,
+}
+
+impl ClientBuilder {
+    /// Creates a new, empty [`ClientBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `.ROBLOSECURITY` cookie used to authenticate cookie-based endpoints.
+    pub fn roblosecurity(mut self, roblosecurity: String) -> Self {
+        self.roblosecurity = Some(roblosecurity);
+        self
+    }
+
+    /// Sets the Open Cloud API key used to authenticate
+    /// [`Client::upload_studio_asset_opencloud`](crate::Client::upload_studio_asset_opencloud).
+    pub fn api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Builds the [`Client`].
+    pub fn build(self) -> Client {
+        Client {
+            reqwest_client: reqwest::Client::new(),
+            roblosecurity: self.roblosecurity,
+            api_key: self.api_key,
+            xcsrf: Arc::new(RwLock::new(String::new())),
+        }
+    }
+}
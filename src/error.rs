@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// The error type used by this crate.
+#[derive(Debug)]
+pub enum RoboatError {
+    /// Used when an endpoint is hit without a `.ROBLOSECURITY` cookie or API key set, but one is
+    /// required.
+    MissingAuth,
+    /// Used when a request fails due to an expired or invalid X-CSRF token. The new token is
+    /// attached so the caller can retry with it.
+    InvalidXcsrf(String),
+    /// Used when a request returns a non-success response that isn't covered by a more specific
+    /// variant.
+    ResponseError(String),
+    /// Used when the underlying `reqwest` request itself fails (connection issues, timeouts,
+    /// malformed responses, etc.).
+    ReqwestError(reqwest::Error),
+    /// Used when an asset's declared [`crate::catalog::AssetType`] doesn't match its sniffed
+    /// content, or isn't supported by the endpoint it's being uploaded through.
+    AssetTypeMismatch(String),
+}
+
+impl fmt::Display for RoboatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingAuth => write!(
+                f,
+                "a .ROBLOSECURITY cookie or API key is required for this endpoint"
+            ),
+            Self::InvalidXcsrf(_) => write!(f, "invalid x-csrf token, retry with the new one"),
+            Self::ResponseError(message) => write!(f, "{message}"),
+            Self::ReqwestError(e) => write!(f, "{e}"),
+            Self::AssetTypeMismatch(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for RoboatError {}
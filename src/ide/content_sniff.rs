@@ -0,0 +1,110 @@
+//! Maps [`AssetType`] to the string/content-type Roblox's upload endpoints expect, and sniffs
+//! `asset_data`'s magic bytes to catch a declared type that doesn't match the actual bytes.
+
+use crate::{catalog::AssetType, RoboatError};
+
+/// The asset kinds this module can detect from `asset_data`'s magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SniffedKind {
+    Png,
+    Jpeg,
+    Ogg,
+    Mp3,
+    AnimationXml,
+}
+
+impl SniffedKind {
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Ogg => "audio/ogg",
+            Self::Mp3 => "audio/mpeg",
+            Self::AnimationXml => "text/xml",
+        }
+    }
+}
+
+/// Returns the `assetTypeName` Roblox's IDE/Open Cloud upload endpoints expect for an
+/// IDE-supported upload kind, or [`RoboatError::AssetTypeMismatch`] if `asset_type` isn't one of
+/// the kinds the IDE upload endpoints accept.
+pub(crate) fn asset_type_name(asset_type: &AssetType) -> Result<&'static str, RoboatError> {
+    match asset_type {
+        AssetType::Animation => Ok("Animation"),
+        AssetType::Audio => Ok("Audio"),
+        AssetType::Decal => Ok("Decal"),
+        AssetType::Image => Ok("Image"),
+        AssetType::Mesh => Ok("Mesh"),
+        _ => Err(RoboatError::AssetTypeMismatch(format!(
+            "{asset_type:?} is not supported by the IDE upload endpoints"
+        ))),
+    }
+}
+
+/// Sniffs `asset_data`'s magic bytes and checks them against `asset_type`, returning the
+/// `Content-Type` to upload the bytes with.
+///
+/// Bytes that don't match any known magic number are passed through unchecked, since sniffing is
+/// best-effort; only a confident, contradicting sniff result is treated as an error.
+pub(crate) fn validate_and_content_type(
+    asset_type: &AssetType,
+    asset_data: &[u8],
+) -> Result<&'static str, RoboatError> {
+    let Some(sniffed) = sniff(asset_data) else {
+        return Ok(fallback_content_type(asset_type));
+    };
+
+    if !matches_declared_type(asset_type, sniffed) {
+        return Err(RoboatError::AssetTypeMismatch(format!(
+            "asset_data looks like {sniffed:?} but asset_type was {asset_type:?}"
+        )));
+    }
+
+    Ok(sniffed.content_type())
+}
+
+fn matches_declared_type(asset_type: &AssetType, sniffed: SniffedKind) -> bool {
+    match asset_type {
+        AssetType::Animation => sniffed == SniffedKind::AnimationXml,
+        AssetType::Audio => matches!(sniffed, SniffedKind::Ogg | SniffedKind::Mp3),
+        AssetType::Decal | AssetType::Image => {
+            matches!(sniffed, SniffedKind::Png | SniffedKind::Jpeg)
+        }
+        _ => true,
+    }
+}
+
+fn fallback_content_type(asset_type: &AssetType) -> &'static str {
+    match asset_type {
+        AssetType::Animation => "text/xml",
+        AssetType::Audio => "audio/ogg",
+        AssetType::Decal | AssetType::Image => "image/png",
+        _ => "application/octet-stream",
+    }
+}
+
+fn sniff(data: &[u8]) -> Option<SniffedKind> {
+    if data.starts_with(b"\x89PNG") {
+        Some(SniffedKind::Png)
+    } else if data.starts_with(b"\xFF\xD8") {
+        Some(SniffedKind::Jpeg)
+    } else if data.starts_with(b"OggS") {
+        Some(SniffedKind::Ogg)
+    } else if data.starts_with(b"ID3") || is_mp3_frame_sync(data) {
+        Some(SniffedKind::Mp3)
+    } else if data.starts_with(b"<KeyframeSequence") {
+        // Note: a bare `<roblox` prefix is *not* treated as a confident animation marker. That's
+        // the generic root element Roblox wraps every decoded asset in (decals, audio, meshes
+        // included), not just animations, so on its own it isn't evidence of a type mismatch -
+        // see the restore module, which frequently re-uploads non-animation assets whose fetched
+        // bytes are this wrapper XML.
+        Some(SniffedKind::AnimationXml)
+    } else {
+        None
+    }
+}
+
+/// An MP3 frame sync is 11 set bits: `0xFF` followed by a byte with its top 3 bits set.
+fn is_mp3_frame_sync(data: &[u8]) -> bool {
+    data.len() >= 2 && data[0] == 0xFF && data[1] & 0xE0 == 0xE0
+}
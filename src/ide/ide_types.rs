@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use serde::Deserialize;
 
 use crate::catalog::AssetType;
 
@@ -13,3 +14,13 @@ pub struct NewStudioAsset {
     pub group_id: Option<u64>,
     pub place_id: Option<u64>,
 }
+
+/// The place and universe created by [`crate::Client::create_place`].
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceCreation {
+    /// The ID of the newly created place.
+    pub place_id: u64,
+    /// The ID of the universe the place belongs to.
+    pub universe_id: u64,
+}
@@ -1,9 +1,19 @@
-use crate::{ide::ide_types::NewStudioAsset, Client, RoboatError};
+use crate::{
+    ide::ide_types::{NewStudioAsset, PlaceCreation},
+    Client, RoboatError,
+};
 
+mod content_sniff;
 /// Types for all the IDE API
 pub mod ide_types;
+/// A cookie-free upload path using Roblox's Open Cloud Assets API.
+pub mod opencloud;
+/// Restores whole decoded Roblox model/place files by re-uploading every asset they reference.
+pub mod restore;
 
 const STUDIO_UPLOAD_API: &str = "https://www.roblox.com/ide/publish/uploadnewanimation";
+const STUDIO_UPDATE_API: &str = "https://www.roblox.com/ide/publish/uploadexistinganimation";
+const PLACE_CREATE_API: &str = "https://www.roblox.com/ide/places/createV2";
 
 // IDE is used for private APIs like ide/uploadnewanimation and ide/places/createV2
 
@@ -76,11 +86,148 @@ impl Client {
             Err(e) => Err(e),
         }
     }
+
+    /// Overwrites the content of an existing asset using the internal `ide/publish/uploadexistinganimation` endpoint.
+    ///
+    /// Unlike [`Client::upload_studio_asset`], this does not mint a new asset ID. It republishes
+    /// `asset_info` onto `asset_id`, so tooling can keep re-deploying to a single canonical asset
+    /// instead of accumulating a new ID on every build.
+    ///
+    /// # Endpoint
+    /// Sends a `POST` request to `https://www.roblox.com/ide/publish/uploadexistinganimation`
+    /// with `asset_id` and animation/audio/image metadata as query parameters and the binary
+    /// data in the body.
+    ///
+    /// # Notes
+    /// * Requires a valid `.ROBLOSECURITY` cookie for authentication.
+    /// * You must already own `asset_id`.
+    /// * If the X-CSRF token is expired or invalid, it will retry the request once with a refreshed token.
+    ///
+    /// # Return Value Notes
+    /// * Returns `asset_id` unchanged if the asset was updated successfully.
+    /// * Or Returns an error.
+    ///
+    /// # Errors
+    /// * [RoboatError::MissingAuth] – If the `.ROBLOSECURITY` cookie is missing.
+    /// * [RoboatError::InvalidXcsrf] – If the CSRF token needs refreshing (retry will be attempted).
+    /// * [RoboatError::ReqwestError] – For any network issues.
+    /// * [RoboatError::ResponseError] – If Roblox returns a failure response.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use bytes::Bytes;
+    /// use roboat::{ide::ide_types::NewStudioAsset, ClientBuilder};
+    ///
+    /// const ROBLOSECURITY: &str = "your_.ROBLOSECURITY_cookie";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new()
+    ///     .roblosecurity(ROBLOSECURITY.to_string())
+    ///     .build();
+    ///
+    /// let animation = NewStudioAsset {
+    ///     name: "MyCoolAnimation".to_string(),
+    ///     description: "A test animation created by Roboat.".to_string(),
+    ///     group_id: Some(123456),
+    ///     place_id: None,
+    ///     asset_type: roboat::catalog::AssetType::Animation,
+    ///     asset_data: Bytes::from_static(b"<KeyframeSequence>...</KeyframeSequence>"),
+    /// };
+    ///
+    /// let asset_id = client.update_studio_asset(123456789, animation).await?;
+    ///
+    /// println!("Updated asset {asset_id}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_studio_asset(
+        &self,
+        asset_id: u64,
+        asset_info: NewStudioAsset,
+    ) -> Result<u64, RoboatError> {
+        match self
+            .update_studio_asset_internal(asset_id, asset_info.clone())
+            .await
+        {
+            Ok(_) => Ok(asset_id),
+            Err(RoboatError::InvalidXcsrf(new_xcsrf)) => {
+                self.set_xcsrf(new_xcsrf).await;
+                self.update_studio_asset_internal(asset_id, asset_info)
+                    .await?;
+                Ok(asset_id)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a new place using the internal `ide/places/createV2` endpoint.
+    ///
+    /// Useful for scaffolding or restoring a game to host assets uploaded via
+    /// [`Client::upload_studio_asset`] or [`Client::restore_place`].
+    ///
+    /// # Endpoint
+    /// Sends a `POST` request to `https://www.roblox.com/ide/places/createV2`.
+    ///
+    /// # Notes
+    /// * Requires a valid `.ROBLOSECURITY` cookie for authentication.
+    /// * If `template_place_id` is `None`, an empty baseplate place is created.
+    /// * If the X-CSRF token is expired or invalid, it will retry the request once with a refreshed token.
+    ///
+    /// # Errors
+    /// * [RoboatError::MissingAuth] – If the `.ROBLOSECURITY` cookie is missing.
+    /// * [RoboatError::InvalidXcsrf] – If the CSRF token needs refreshing (retry will be attempted).
+    /// * [RoboatError::ReqwestError] – For any network issues.
+    /// * [RoboatError::ResponseError] – If Roblox returns a failure response.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "your_.ROBLOSECURITY_cookie";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new()
+    ///     .roblosecurity(ROBLOSECURITY.to_string())
+    ///     .build();
+    ///
+    /// let place = client
+    ///     .create_place(None, "My Restored Game".to_string(), None)
+    ///     .await?;
+    ///
+    /// println!("Created place {} in universe {}", place.place_id, place.universe_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_place(
+        &self,
+        template_place_id: Option<u64>,
+        name: String,
+        group_id: Option<u64>,
+    ) -> Result<PlaceCreation, RoboatError> {
+        match self
+            .create_place_internal(template_place_id, name.clone(), group_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(RoboatError::InvalidXcsrf(new_xcsrf)) => {
+                self.set_xcsrf(new_xcsrf).await;
+                self.create_place_internal(template_place_id, name, group_id)
+                    .await
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 mod internal {
     use crate::{
-        ide::{ide_types::NewStudioAsset, STUDIO_UPLOAD_API},
+        ide::{
+            content_sniff,
+            ide_types::{NewStudioAsset, PlaceCreation},
+            PLACE_CREATE_API, STUDIO_UPDATE_API, STUDIO_UPLOAD_API,
+        },
         Client, RoboatError, XCSRF_HEADER,
     };
     use reqwest::header::{self, USER_AGENT};
@@ -92,9 +239,14 @@ mod internal {
             let cookie = self.cookie_string()?;
             let xcsrf = self.xcsrf().await;
 
-            // asset_info.asset_type.into(str)
+            let asset_type_name = content_sniff::asset_type_name(&asset_info.asset_type)?;
+            let content_type = content_sniff::validate_and_content_type(
+                &asset_info.asset_type,
+                &asset_info.asset_data,
+            )?;
+
             let mut query_params = vec![
-                ("assetTypeName", format!("{:?}", asset_info.asset_type)),
+                ("assetTypeName", asset_type_name.to_string()),
                 ("name", asset_info.name.clone()),
                 ("description", asset_info.description.clone()),
                 ("AllID", "1".to_string()),
@@ -113,6 +265,7 @@ mod internal {
                 .post(STUDIO_UPLOAD_API)
                 .query(&query_params)
                 .header(header::COOKIE, cookie)
+                .header(header::CONTENT_TYPE, content_type)
                 .body(asset_info.asset_data)
                 .header(XCSRF_HEADER, xcsrf)
                 .header(USER_AGENT, "Roblox/WinInet")
@@ -123,5 +276,80 @@ mod internal {
             let response_id = response.text().await.map_err(RoboatError::ReqwestError)?;
             Ok(response_id)
         }
+
+        pub(super) async fn update_studio_asset_internal(
+            &self,
+            asset_id: u64,
+            asset_info: NewStudioAsset,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let asset_type_name = content_sniff::asset_type_name(&asset_info.asset_type)?;
+            let content_type = content_sniff::validate_and_content_type(
+                &asset_info.asset_type,
+                &asset_info.asset_data,
+            )?;
+
+            let query_params = vec![
+                ("assetId", asset_id.to_string()),
+                ("assetTypeName", asset_type_name.to_string()),
+                ("name", asset_info.name.clone()),
+                ("description", asset_info.description.clone()),
+            ];
+
+            let request_result = self
+                .reqwest_client
+                .post(STUDIO_UPDATE_API)
+                .query(&query_params)
+                .header(header::COOKIE, cookie)
+                .header(header::CONTENT_TYPE, content_type)
+                .body(asset_info.asset_data)
+                .header(XCSRF_HEADER, xcsrf)
+                .header(USER_AGENT, "Roblox/WinInet")
+                .send()
+                .await;
+
+            Self::validate_request_result(request_result).await?;
+            Ok(())
+        }
+
+        pub(super) async fn create_place_internal(
+            &self,
+            template_place_id: Option<u64>,
+            name: String,
+            group_id: Option<u64>,
+        ) -> Result<PlaceCreation, RoboatError> {
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let mut query_params = vec![("name", name)];
+
+            if let Some(template_place_id) = template_place_id {
+                query_params.push(("templatePlaceId", template_place_id.to_string()));
+            }
+
+            if let Some(group_id) = group_id {
+                query_params.push(("groupId", group_id.to_string()));
+            }
+
+            let request_result = self
+                .reqwest_client
+                .post(PLACE_CREATE_API)
+                .query(&query_params)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .header(USER_AGENT, "Roblox/WinInet")
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let place_creation = response
+                .json::<PlaceCreation>()
+                .await
+                .map_err(RoboatError::ReqwestError)?;
+
+            Ok(place_creation)
+        }
     }
 }
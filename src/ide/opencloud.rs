@@ -0,0 +1,254 @@
+//! An alternative, cookie-free upload path using Roblox's Open Cloud Assets API.
+
+use reqwest::multipart;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{
+    ide::{content_sniff, ide_types::NewStudioAsset},
+    Client, RoboatError,
+};
+
+const OPEN_CLOUD_ASSETS_API: &str = "https://apis.roblox.com/assets/v1/assets";
+const OPEN_CLOUD_OPERATIONS_API: &str = "https://apis.roblox.com/assets/v1/operations";
+const API_KEY_HEADER: &str = "x-api-key";
+
+const OPERATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const OPERATION_POLL_ATTEMPTS: u32 = 30;
+
+/// Who an asset uploaded via [`Client::upload_studio_asset_opencloud`] should be created under.
+#[derive(Debug, Clone, Copy)]
+pub enum AssetCreator {
+    /// Create the asset under this user.
+    User(u64),
+    /// Create the asset under this group.
+    Group(u64),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Creator {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_id: Option<String>,
+}
+
+impl From<AssetCreator> for Creator {
+    fn from(creator: AssetCreator) -> Self {
+        match creator {
+            AssetCreator::User(user_id) => Self {
+                user_id: Some(user_id.to_string()),
+                group_id: None,
+            },
+            AssetCreator::Group(group_id) => Self {
+                user_id: None,
+                group_id: Some(group_id.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreationContext {
+    creator: Creator,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AssetCreateRequest {
+    asset_type: String,
+    display_name: String,
+    description: String,
+    creation_context: CreationContext,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Operation {
+    /// Roblox doesn't echo an `operationId` field on either the creation response or the
+    /// `operations/{id}` poll response — both only return `path` (e.g. `"operations/123"`), so
+    /// the ID has to be parsed back out of it.
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    response: Option<OperationResponse>,
+}
+
+impl Operation {
+    /// Pulls the bare operation ID out of `path` (`"operations/123"` -> `"123"`).
+    fn id(&self) -> Option<&str> {
+        self.path
+            .as_deref()
+            .map(|path| path.rsplit('/').next().unwrap_or(path))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OperationResponse {
+    #[serde(rename = "assetId")]
+    asset_id: String,
+}
+
+impl Client {
+    /// Uploads a new asset to Roblox using the Open Cloud Assets API, authenticating with an
+    /// API key instead of a `.ROBLOSECURITY` cookie.
+    ///
+    /// This is a cookie-free alternative to [`Client::upload_studio_asset`], intended for
+    /// server-side automation that would otherwise have to deal with CSRF refresh churn.
+    ///
+    /// # Endpoint
+    /// Sends a `multipart/form-data` `POST` request to `https://apis.roblox.com/assets/v1/assets`,
+    /// then polls `https://apis.roblox.com/assets/v1/operations/{id}` until the upload finishes.
+    ///
+    /// # Notes
+    /// * Requires an API key to be set on the client (see [`crate::ClientBuilder::api_key`]).
+    /// * This polls the operation at most [`OPERATION_POLL_ATTEMPTS`] times, sleeping
+    ///   [`OPERATION_POLL_INTERVAL`] between attempts, before giving up.
+    ///
+    /// # Return Value Notes
+    /// * Returns `String` of the new asset ID if the asset was uploaded successfully.
+    /// * Or Returns an error.
+    ///
+    /// # Errors
+    /// * [RoboatError::MissingAuth] – If no API key is set on the client.
+    /// * [RoboatError::ReqwestError] – For any network issues.
+    /// * [RoboatError::ResponseError] – If Roblox returns a failure response, or the operation
+    ///   doesn't finish within the poll budget.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use bytes::Bytes;
+    /// use roboat::{
+    ///     ide::{ide_types::NewStudioAsset, opencloud::AssetCreator},
+    ///     ClientBuilder,
+    /// };
+    ///
+    /// const API_KEY: &str = "your_open_cloud_api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let animation = NewStudioAsset {
+    ///     name: "MyCoolAnimation".to_string(),
+    ///     description: "A test animation created by Roboat.".to_string(),
+    ///     group_id: None,
+    ///     place_id: None,
+    ///     asset_type: roboat::catalog::AssetType::Animation,
+    ///     asset_data: Bytes::from_static(b"<KeyframeSequence>...</KeyframeSequence>"),
+    /// };
+    ///
+    /// let asset_id = client
+    ///     .upload_studio_asset_opencloud(animation, AssetCreator::User(123456))
+    ///     .await?;
+    ///
+    /// println!("Uploaded asset {asset_id}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_studio_asset_opencloud(
+        &self,
+        asset_info: NewStudioAsset,
+        creator: AssetCreator,
+    ) -> Result<String, RoboatError> {
+        let api_key = self.api_key()?;
+
+        let asset_type_name = content_sniff::asset_type_name(&asset_info.asset_type)?;
+        let content_type = content_sniff::validate_and_content_type(
+            &asset_info.asset_type,
+            &asset_info.asset_data,
+        )?;
+
+        let create_request = AssetCreateRequest {
+            asset_type: asset_type_name.to_string(),
+            display_name: asset_info.name.clone(),
+            description: asset_info.description.clone(),
+            creation_context: CreationContext {
+                creator: creator.into(),
+            },
+        };
+
+        // `AssetCreateRequest` is built entirely from our own owned `String`/`Option` fields, so
+        // serialization cannot fail.
+        let request_json = serde_json::to_string(&create_request)
+            .expect("AssetCreateRequest is always valid json");
+
+        let form = multipart::Form::new().text("request", request_json).part(
+            "fileContent",
+            multipart::Part::bytes(asset_info.asset_data.to_vec())
+                .file_name("asset")
+                .mime_str(content_type)
+                .map_err(RoboatError::ReqwestError)?,
+        );
+
+        let request_result = self
+            .reqwest_client
+            .post(OPEN_CLOUD_ASSETS_API)
+            .header(API_KEY_HEADER, &api_key)
+            .multipart(form)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let operation = response
+            .json::<Operation>()
+            .await
+            .map_err(RoboatError::ReqwestError)?;
+
+        self.poll_asset_operation(&api_key, operation).await
+    }
+
+    async fn poll_asset_operation(
+        &self,
+        api_key: &str,
+        mut operation: Operation,
+    ) -> Result<String, RoboatError> {
+        if !operation.done {
+            let operation_id = operation.id().map(str::to_string).ok_or_else(|| {
+                RoboatError::ResponseError(
+                    "Open Cloud asset creation response had no operation path".to_string(),
+                )
+            })?;
+
+            for _ in 0..OPERATION_POLL_ATTEMPTS {
+                if operation.done {
+                    break;
+                }
+
+                tokio::time::sleep(OPERATION_POLL_INTERVAL).await;
+
+                let operation_url = format!("{OPEN_CLOUD_OPERATIONS_API}/{operation_id}");
+
+                let request_result = self
+                    .reqwest_client
+                    .get(operation_url)
+                    .header(API_KEY_HEADER, api_key)
+                    .send()
+                    .await;
+
+                let response = Self::validate_request_result(request_result).await?;
+                operation = response
+                    .json::<Operation>()
+                    .await
+                    .map_err(RoboatError::ReqwestError)?;
+            }
+        }
+
+        let operation_id = operation.id().unwrap_or("<unknown>").to_string();
+        let done = operation.done;
+
+        operation
+            .response
+            .map(|operation_response| operation_response.asset_id)
+            .ok_or_else(|| {
+                RoboatError::ResponseError(if done {
+                    format!("operation {operation_id} finished without an asset ID")
+                } else {
+                    format!("operation {operation_id} did not finish within the poll budget")
+                })
+            })
+    }
+}
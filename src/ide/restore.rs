@@ -0,0 +1,231 @@
+//! Restores a decoded Roblox model/place file whose asset references (animations, sounds,
+//! textures/decals) are owned by an account other than the caller's, by re-uploading every
+//! referenced asset and rewriting the file to point at the newly owned IDs.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{catalog::AssetType, ide::ide_types::NewStudioAsset, Client, RoboatError};
+
+/// The property tags this module knows how to find asset references in, and the [`AssetType`]
+/// each one re-uploads as.
+///
+/// There is no `"Decal"` entry here: a decal's image is stored under the `Texture` property, not
+/// a property literally named `Decal`, so that tag never appears as a real reference.
+const TRACKED_PROPERTIES: &[(&str, AssetType)] = &[
+    ("AnimationId", AssetType::Animation),
+    ("SoundId", AssetType::Audio),
+    ("Texture", AssetType::Decal),
+    ("MeshId", AssetType::Mesh),
+];
+
+const RBXASSETID_PREFIX: &str = "rbxassetid://";
+const ASSET_URL_PREFIX: &str = "http://www.roblox.com/asset/?id=";
+
+/// Why a single asset reference could not be restored.
+#[derive(Debug)]
+pub enum AssetRestoreError {
+    /// [`Client::get_asset_info`], [`Client::fetch_asset_data`] or
+    /// [`Client::upload_studio_asset`] failed for this asset.
+    Roboat(RoboatError),
+    /// The re-uploaded asset ID Roblox returned could not be parsed as a `u64`.
+    MalformedAssetId(String),
+}
+
+impl std::fmt::Display for AssetRestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Roboat(e) => write!(f, "{e}"),
+            Self::MalformedAssetId(id) => write!(f, "asset id {id} was not a valid u64"),
+        }
+    }
+}
+
+/// One asset reference that could not be restored.
+#[derive(Debug)]
+pub struct AssetRestoreFailure {
+    /// The asset ID as it appeared in the source file.
+    pub old_asset_id: u64,
+    /// Why the restore failed. The reference is left pointing at `old_asset_id` in the output.
+    pub error: AssetRestoreError,
+}
+
+/// A report of every asset reference `Client::restore_place` found and what happened to it.
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    /// Maps every successfully restored old asset ID to the freshly uploaded ID that replaces it.
+    /// IDs that failed to restore are listed in `failures` instead.
+    pub id_map: HashMap<u64, u64>,
+    /// Asset references that couldn't be restored and were left unchanged.
+    pub failures: Vec<AssetRestoreFailure>,
+}
+
+/// A single asset reference found while scanning the file, recorded as a byte span of its
+/// numeric ID so it can be rewritten in place.
+struct AssetRef {
+    id_start: usize,
+    id_end: usize,
+    old_asset_id: u64,
+    asset_type: AssetType,
+}
+
+impl Client {
+    /// Restores every `AnimationId`, `SoundId`, `Texture` and `MeshId` asset reference in a
+    /// decoded Roblox model/place XML file by re-uploading each referenced asset under the
+    /// current account and rewriting the references to point at the new, owned IDs.
+    ///
+    /// # Notes
+    /// * `xml` is the full contents of a decoded `.rblx`/`.rbxm` (XML variant) file.
+    /// * Each referenced asset is only fetched and re-uploaded once, even if several instances
+    ///   share it.
+    /// * Asset references that fail to restore (asset deleted, upload rejected, etc.) are
+    ///   recorded in the returned [`RestoreReport`] instead of aborting the whole restore, and
+    ///   are left pointing at their original ID in the returned file.
+    ///
+    /// # Errors
+    /// This only returns `Err` for failures unrelated to any specific asset, such as a missing
+    /// `.ROBLOSECURITY` cookie. Per-asset failures are reported in [`RestoreReport::failures`].
+    ///
+    /// * [RoboatError::MissingAuth] – If the `.ROBLOSECURITY` cookie is missing.
+    pub async fn restore_place(
+        &self,
+        xml: &str,
+    ) -> Result<(String, RestoreReport), RoboatError> {
+        self.cookie_string()?;
+
+        let asset_refs = find_asset_refs(xml);
+
+        let mut report = RestoreReport::default();
+        let mut attempted = HashSet::new();
+
+        for asset_ref in &asset_refs {
+            if !attempted.insert(asset_ref.old_asset_id) {
+                continue;
+            }
+
+            match self
+                .restore_single_asset(asset_ref.old_asset_id, asset_ref.asset_type.clone())
+                .await
+            {
+                Ok(new_asset_id) => {
+                    report.id_map.insert(asset_ref.old_asset_id, new_asset_id);
+                }
+                Err(error) => report.failures.push(AssetRestoreFailure {
+                    old_asset_id: asset_ref.old_asset_id,
+                    error,
+                }),
+            }
+        }
+
+        let restored_xml = rewrite_asset_refs(xml, &asset_refs, &report.id_map);
+
+        Ok((restored_xml, report))
+    }
+
+    async fn restore_single_asset(
+        &self,
+        old_asset_id: u64,
+        asset_type: AssetType,
+    ) -> Result<u64, AssetRestoreError> {
+        // Checked first, ahead of the data fetch below: a deleted or moderated asset fails here
+        // cheaply, instead of after downloading its (possibly large) `asset_data` for nothing.
+        self.get_asset_info(old_asset_id)
+            .await
+            .map_err(AssetRestoreError::Roboat)?;
+
+        let asset_data = self
+            .fetch_asset_data(old_asset_id)
+            .await
+            .map_err(AssetRestoreError::Roboat)?;
+
+        let new_asset = NewStudioAsset {
+            name: format!("restored_{old_asset_id}"),
+            description: "Restored by roboat-extras".to_string(),
+            asset_type,
+            asset_data,
+            group_id: None,
+            place_id: None,
+        };
+
+        let new_asset_id = self
+            .upload_studio_asset(new_asset)
+            .await
+            .map_err(AssetRestoreError::Roboat)?;
+
+        new_asset_id
+            .trim()
+            .parse()
+            .map_err(|_| AssetRestoreError::MalformedAssetId(new_asset_id))
+    }
+}
+
+fn find_asset_refs(xml: &str) -> Vec<AssetRef> {
+    let mut asset_refs = Vec::new();
+
+    for (property, asset_type) in TRACKED_PROPERTIES {
+        let marker = format!("name=\"{property}\"");
+        let mut search_from = 0;
+
+        while let Some(relative_marker) = xml[search_from..].find(&marker) {
+            let marker_start = search_from + relative_marker;
+            search_from = marker_start + marker.len();
+
+            if let Some((id_start, id_end, old_asset_id)) =
+                find_next_asset_id(xml, search_from)
+            {
+                asset_refs.push(AssetRef {
+                    id_start,
+                    id_end,
+                    old_asset_id,
+                    asset_type: asset_type.clone(),
+                });
+            }
+        }
+    }
+
+    asset_refs.sort_by_key(|asset_ref| asset_ref.id_start);
+    asset_refs
+}
+
+/// Looks for the next `rbxassetid://N` or `http://www.roblox.com/asset/?id=N` reference at or
+/// after `from`, bounded to the same property element, and returns the byte span of the digits.
+fn find_next_asset_id(xml: &str, from: usize) -> Option<(usize, usize, u64)> {
+    // A property's value always appears before the next property starts, so bound the search to
+    // that window rather than scanning the whole rest of the file.
+    let window_end = xml[from..].find("name=\"").map_or(xml.len(), |i| from + i);
+    let window = &xml[from..window_end];
+
+    for prefix in [RBXASSETID_PREFIX, ASSET_URL_PREFIX] {
+        if let Some(relative_prefix) = window.find(prefix) {
+            let id_start = from + relative_prefix + prefix.len();
+            let id_end = xml[id_start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(xml.len(), |i| id_start + i);
+
+            if let Ok(old_asset_id) = xml[id_start..id_end].parse() {
+                return Some((id_start, id_end, old_asset_id));
+            }
+        }
+    }
+
+    None
+}
+
+fn rewrite_asset_refs(xml: &str, asset_refs: &[AssetRef], id_map: &HashMap<u64, u64>) -> String {
+    let mut restored = String::with_capacity(xml.len());
+    let mut cursor = 0;
+
+    for asset_ref in asset_refs {
+        restored.push_str(&xml[cursor..asset_ref.id_start]);
+
+        let new_asset_id = id_map
+            .get(&asset_ref.old_asset_id)
+            .copied()
+            .unwrap_or(asset_ref.old_asset_id);
+        restored.push_str(&new_asset_id.to_string());
+
+        cursor = asset_ref.id_end;
+    }
+
+    restored.push_str(&xml[cursor..]);
+    restored
+}
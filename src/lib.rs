@@ -0,0 +1,12 @@
+//! A minimal, incomplete stand-in for the rest of the `roboat` crate, just enough for the
+//! `ide` module's Open Cloud API key support to resolve.
+
+mod client;
+mod error;
+
+pub use client::{Client, ClientBuilder};
+pub use error::RoboatError;
+
+pub mod ide;
+
+pub(crate) const XCSRF_HEADER: &str = "X-CSRF-TOKEN";